@@ -1,5 +1,6 @@
 use super::{
-    cmd::{RsysCmd, RsysOpt},
+    cmd::{GraphCmd, RsysCmd, RsysOpt},
+    config::{DefaultFormat, RsysConfig},
     util::PrintFormat,
 };
 use rsys::{Result, Rsys};
@@ -8,12 +9,26 @@ use structopt::StructOpt;
 pub struct RsysCli {
     pub opts: RsysOpt,
     pub system: Rsys,
+    pub config: RsysConfig,
 }
 impl RsysCli {
     pub fn new() -> RsysCli {
+        let opts = RsysOpt::from_args();
+        let config = opts
+            .config
+            .as_ref()
+            .map(|path| RsysConfig::load_or_create(path))
+            .transpose()
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to load config - {}", e);
+                None
+            })
+            .unwrap_or_default();
+
         RsysCli {
-            opts: RsysOpt::from_args(),
+            opts,
             system: Rsys::new(),
+            config,
         }
     }
 
@@ -26,14 +41,8 @@ impl RsysCli {
                     yaml,
                     pretty,
                 } => {
-                    let format = if *json {
-                        PrintFormat::Json
-                    } else if *yaml {
-                        PrintFormat::Yaml
-                    } else {
-                        PrintFormat::Normal
-                    };
-                    self.get(property, format, *pretty)?
+                    let format = self.format(*json, *yaml);
+                    self.get(property, format, self.pretty(*pretty))?
                 }
                 RsysCmd::Dump {
                     json,
@@ -48,15 +57,18 @@ impl RsysCli {
                     stats,
                     processes,
                 } => {
-                    let format = if *json {
-                        PrintFormat::Json
-                    } else if *yaml {
-                        PrintFormat::Yaml
-                    } else {
-                        PrintFormat::Normal
-                    };
+                    let format = self.format(*json, *yaml);
                     self.dump(
-                        format, *pretty, *cpu, *memory, *network, *storage, *mounts, *all, *stats, *processes,
+                        format,
+                        self.pretty(*pretty),
+                        *cpu,
+                        *memory,
+                        *network,
+                        *storage,
+                        *mounts,
+                        *all,
+                        *stats,
+                        *processes,
                     )?
                 }
                 RsysCmd::Watch {
@@ -70,12 +82,48 @@ impl RsysCli {
                     duration,
                     interval,
                 } => self.watch(
-                    *pretty, *cpu, *memory, *network, *storage, *all, *stats, *duration, *interval,
+                    self.pretty(*pretty),
+                    *cpu,
+                    *memory,
+                    *network,
+                    *storage,
+                    *all,
+                    *stats,
+                    *duration,
+                    *interval,
                 )?,
-                RsysCmd::Graph { graph: cmd } => self.graph(cmd.clone()),
+                RsysCmd::Graph { graph: cmd } => {
+                    let cmd = cmd
+                        .clone()
+                        .or_else(|| self.config.default_graph.as_deref().and_then(GraphCmd::from_name))
+                        .unwrap_or(GraphCmd::Cpu { tick_rate: None });
+                    self.graph(cmd)
+                }
             }
         }
 
         Ok(())
     }
+
+    /// Resolves the print format for `Get`/`Dump`, preferring an explicit
+    /// `--json`/`--yaml` flag over the config file's `default_format`.
+    fn format(&self, json: bool, yaml: bool) -> PrintFormat {
+        if json {
+            PrintFormat::Json
+        } else if yaml {
+            PrintFormat::Yaml
+        } else {
+            match self.config.default_format {
+                Some(DefaultFormat::Json) => PrintFormat::Json,
+                Some(DefaultFormat::Yaml) => PrintFormat::Yaml,
+                Some(DefaultFormat::Normal) | None => PrintFormat::Normal,
+            }
+        }
+    }
+
+    /// Resolves whether output should be pretty-printed: `--pretty` always
+    /// wins when set, otherwise falls back to the config file's `pretty`.
+    fn pretty(&self, pretty: bool) -> bool {
+        pretty || self.config.pretty.unwrap_or(false)
+    }
 }
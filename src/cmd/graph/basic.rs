@@ -0,0 +1,120 @@
+use super::cpu::CpuMonitor;
+use crate::util::conv_hz;
+use anyhow::{anyhow, Result};
+use rsys::linux::memory::memory;
+use tui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+const TICK_RATE: u64 = 1000;
+const COLUMNS: usize = 4;
+
+/// Condensed, graphless readout: per-core frequencies as a compact text
+/// grid plus memory/swap usage as [`Gauge`] bars, updated on the same tick
+/// loop as the full graph widgets. Reuses `CpuMonitor`'s data collection and
+/// skips `Chart` rendering entirely, mirroring bottom's basic mode for
+/// terminals too small for graphs.
+pub(crate) struct BasicMonitor {
+    cpu: CpuMonitor,
+    mem_percent: f64,
+    swap_percent: f64,
+}
+
+impl BasicMonitor {
+    pub(crate) fn new(tick_rate: Option<u64>) -> Result<BasicMonitor> {
+        let cpu = CpuMonitor::new(tick_rate)?;
+        let mut monitor = BasicMonitor {
+            cpu,
+            mem_percent: 0.0,
+            swap_percent: 0.0,
+        };
+        monitor.refresh_memory()?;
+        Ok(monitor)
+    }
+
+    fn refresh_memory(&mut self) -> Result<()> {
+        let memory = memory().map_err(|e| anyhow!("Failed to read memory info - {}", e))?;
+        self.mem_percent = (memory.total - memory.free) as f64 / memory.total as f64 * 100.0;
+        self.swap_percent = if memory.swap_total > 0 {
+            (memory.swap_total - memory.swap_free) as f64 / memory.swap_total as f64 * 100.0
+        } else {
+            0.0
+        };
+        Ok(())
+    }
+
+    fn render_core_grid(&self, f: &mut Frame<crate::cmd::show::common::RsysBackend>, area: Rect) {
+        let block = Block::default().title("CPU Frequencies").borders(Borders::ALL);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let cores = self.cpu.core_readouts();
+        let columns = COLUMNS.min(cores.len().max(1));
+        let col_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(inner);
+
+        for (i, chunk) in col_chunks.iter().enumerate() {
+            let lines: Vec<Spans> = cores
+                .iter()
+                .skip(i)
+                .step_by(columns)
+                .map(|(name, color, freq)| {
+                    Spans::from(vec![
+                        Span::raw(format!("{}: ", name)),
+                        Span::styled(conv_hz(*freq as u64), Style::default().fg(*color)),
+                    ])
+                })
+                .collect();
+            f.render_widget(Paragraph::new(lines), *chunk);
+        }
+    }
+
+    fn render_gauges(&self, f: &mut Frame<crate::cmd::show::common::RsysBackend>, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Length(3)])
+            .split(area);
+
+        let mem_gauge = Gauge::default()
+            .block(Block::default().title("Memory").borders(Borders::ALL))
+            .gauge_style(Style::default().fg(Color::Magenta))
+            .percent(self.mem_percent.round() as u16);
+        f.render_widget(mem_gauge, chunks[0]);
+
+        let swap_gauge = Gauge::default()
+            .block(Block::default().title("Swap").borders(Borders::ALL))
+            .gauge_style(Style::default().fg(Color::Blue))
+            .percent(self.swap_percent.round() as u16);
+        f.render_widget(swap_gauge, chunks[1]);
+    }
+
+    pub(crate) fn graph_loop(tick_rate: Option<u64>) -> Result<()> {
+        let tick_rate = Some(tick_rate.unwrap_or(TICK_RATE));
+        let config = super::events::Config::new_or_default(tick_rate);
+        let mut monitor = BasicMonitor::new(tick_rate)?;
+        crate::cmd::show::common::single_widget_loop(&mut monitor, config)
+    }
+}
+
+impl crate::cmd::show::common::StatefulWidget for BasicMonitor {
+    fn update(&mut self) -> Result<()> {
+        crate::cmd::show::common::StatefulWidget::update(&mut self.cpu)?;
+        self.refresh_memory()
+    }
+
+    fn render_widget(&self, f: &mut Frame<crate::cmd::show::common::RsysBackend>, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(area);
+
+        self.render_core_grid(f, chunks[0]);
+        self.render_gauges(f, chunks[1]);
+    }
+}
@@ -0,0 +1,257 @@
+use super::events::{Config, Event, Events, Key};
+use anyhow::Result;
+use std::io;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Layout, Rect},
+    symbols,
+    Frame, Terminal,
+};
+
+/// Same backend choice as [`crate::cmd::show::common::RsysBackend`], under
+/// its own name for the simple `cmd::graph` widgets; both aliases name the
+/// same type.
+#[cfg(feature = "termion")]
+pub(crate) type GraphBackend =
+    tui::backend::TermionBackend<termion::screen::AlternateScreen<termion::raw::RawTerminal<std::io::Stdout>>>;
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+pub(crate) type GraphBackend = tui::backend::CrosstermBackend<std::io::Stdout>;
+
+pub(crate) type GraphTerminal = Terminal<GraphBackend>;
+
+#[cfg(feature = "termion")]
+pub(crate) fn get_terminal() -> Result<GraphTerminal> {
+    use termion::{raw::IntoRawMode, screen::AlternateScreen};
+    let stdout = io::stdout().into_raw_mode()?;
+    let stdout = AlternateScreen::from(stdout);
+    let backend = tui::backend::TermionBackend::new(stdout);
+    Ok(Terminal::new(backend)?)
+}
+
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+pub(crate) fn get_terminal() -> Result<GraphTerminal> {
+    use crossterm::{
+        execute,
+        terminal::{enable_raw_mode, EnterAlternateScreen},
+    };
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = tui::backend::CrosstermBackend::new(stdout);
+    Ok(Terminal::new(backend)?)
+}
+
+type Point = (f64, f64);
+
+/// Ordered samples backing a single dataset line on a [`Chart`](tui::widgets::Chart).
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DataSeries {
+    points: Vec<Point>,
+}
+impl DataSeries {
+    pub(crate) fn new() -> DataSeries {
+        DataSeries { points: Vec::new() }
+    }
+
+    pub(crate) fn add(&mut self, x: f64, y: f64) {
+        self.points.push((x, y));
+    }
+
+    pub(crate) fn data(&self) -> &[Point] {
+        &self.points
+    }
+
+    pub(crate) fn first(&self) -> Option<&Point> {
+        self.points.first()
+    }
+
+    /// Drops every leading point older than `latest_x - window_secs`, so the
+    /// retained span is always time-bounded rather than a fixed point count
+    /// (bottom's `STALE_MAX_SECONDS` pattern, applied per-series).
+    pub(crate) fn prune_older_than(&mut self, window_secs: f64, latest_x: f64) {
+        let cutoff = latest_x - window_secs;
+        while self.points.first().map_or(false, |p| p.0 < cutoff) {
+            self.points.remove(0);
+        }
+    }
+}
+
+/// Shared x/y axis bounds, tick configuration and marker style for a
+/// [`GraphWidget`]. `y` is tracked as `(min, max)`, widened as new samples
+/// come in.
+#[derive(Debug, Clone)]
+pub(crate) struct Monitor {
+    x: (f64, f64),
+    y: (f64, f64),
+    time: f64,
+    config: Config,
+    marker: symbols::Marker,
+}
+impl Monitor {
+    pub(crate) fn new(x: (f64, f64), y: (f64, f64), config: Config) -> Monitor {
+        Monitor {
+            x,
+            y,
+            time: 0.,
+            config,
+            marker: symbols::Marker::Dot,
+        }
+    }
+
+    pub(crate) fn x(&self) -> (f64, f64) {
+        self.x
+    }
+    pub(crate) fn y(&self) -> (f64, f64) {
+        self.y
+    }
+    pub(crate) fn min_y(&self) -> f64 {
+        self.y.0
+    }
+    pub(crate) fn max_y(&self) -> f64 {
+        self.y.1
+    }
+    pub(crate) fn time(&self) -> f64 {
+        self.time
+    }
+    pub(crate) fn config(&self) -> Config {
+        self.config
+    }
+    pub(crate) fn marker(&self) -> symbols::Marker {
+        self.marker
+    }
+
+    pub(crate) fn add_time(&mut self, delta: f64) {
+        self.time += delta;
+    }
+
+    pub(crate) fn set_if_y_max(&mut self, value: f64) {
+        if value > self.y.1 {
+            self.y.1 = value;
+        }
+    }
+    pub(crate) fn set_if_y_min(&mut self, value: f64) {
+        if value < self.y.0 {
+            self.y.0 = value;
+        }
+    }
+
+    /// Widens (positive `delta`) or narrows (negative `delta`) the visible
+    /// x window, clamped to a sane range so `+`/`-` can't collapse or blow
+    /// out the chart.
+    pub(crate) fn adjust_window(&mut self, delta: f64) {
+        self.x.1 = (self.x.1 + delta).max(self.x.0 + 5.0).min(self.x.0 + 300.0);
+    }
+
+    /// Cycles the dataset marker style, e.g. for a `m` keybinding.
+    pub(crate) fn toggle_marker(&mut self) {
+        self.marker = match self.marker {
+            symbols::Marker::Dot => symbols::Marker::Braille,
+            _ => symbols::Marker::Dot,
+        };
+    }
+
+    /// Prunes every series down to this monitor's visible time window, then
+    /// slides the x-axis bounds to track the oldest surviving point. Replaces
+    /// ad hoc single-point `pop()`-on-overflow logic with a single retention
+    /// window shared by every series, so the graph stays correct regardless
+    /// of tick rate.
+    pub(crate) fn prune_stale<'a>(&mut self, series: impl IntoIterator<Item = &'a mut DataSeries>) {
+        let window = self.x.1 - self.x.0;
+        let latest_x = self.time;
+        let mut front: Option<f64> = None;
+        for s in series {
+            s.prune_older_than(window, latest_x);
+            if let Some(&(x, _)) = s.first() {
+                front = Some(front.map_or(x, |f| f.min(x)));
+            }
+        }
+        if let Some(front_x) = front {
+            self.x.0 = front_x;
+            self.x.1 = front_x + window;
+        }
+    }
+}
+
+/// Trait providing the tick/render contract for the simple, single-metric
+/// monitors under `cmd::graph` (CPU, memory, network, ...).
+pub(crate) trait GraphWidget {
+    fn update(&mut self) -> Result<()>;
+    fn render_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect);
+    fn monitor(&mut self) -> &mut Monitor;
+
+    /// Window resize (`+`/`-`) and marker toggle (`m`), shared by every
+    /// simple graph widget via [`Monitor`]. Returns whether the key was
+    /// handled.
+    fn on_key(&mut self, key: Key) -> bool {
+        match key {
+            Key::Char('+') => {
+                self.monitor().adjust_window(5.0);
+                true
+            }
+            Key::Char('-') => {
+                self.monitor().adjust_window(-5.0);
+                true
+            }
+            Key::Char('m') => {
+                self.monitor().toggle_marker();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drives this widget full-screen until the configured exit key is
+    /// pressed, ticking on the widget's own `Monitor` tick rate.
+    fn _graph_loop(&mut self) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let config = self.monitor().config();
+        let mut terminal = get_terminal()?;
+        let events = Events::with_config(config);
+        let mut err_msg: Option<String> = None;
+        loop {
+            terminal.draw(|f| {
+                let size = f.size();
+                let layout = Layout::default().constraints([Constraint::Percentage(100)]).split(size);
+                self.render_widget(f, layout[0]);
+
+                if let Some(err) = err_msg.clone() {
+                    crate::cmd::show::common::err_popup(f, &err, "Press `q` to quit.");
+                }
+            })?;
+
+            match events.next()? {
+                Event::Input(key) => {
+                    if key == events.exit_key() {
+                        break;
+                    }
+                    self.on_key(key);
+                }
+                Event::Tick => {
+                    if let Err(e) = self.update() {
+                        err_msg = Some(e.to_string());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lets any [`GraphWidget`] sit alongside other widgets in a
+/// [`Dashboard`](crate::cmd::show::common::Dashboard), instead of each
+/// implementer hand-rolling the same passthrough.
+impl<T: GraphWidget> crate::cmd::show::common::StatefulWidget for T {
+    fn update(&mut self) -> Result<()> {
+        GraphWidget::update(self)
+    }
+
+    fn render_widget(&self, f: &mut Frame<crate::cmd::show::common::RsysBackend>, area: Rect) {
+        GraphWidget::render_widget(self, f, area)
+    }
+
+    fn on_key(&mut self, key: Key) -> bool {
+        GraphWidget::on_key(self, key)
+    }
+}
@@ -10,7 +10,6 @@ use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    symbols,
     text::{Span, Spans},
     widgets::{Axis, Block, Borders, Chart, Dataset, Paragraph},
     Frame,
@@ -18,7 +17,7 @@ use tui::{
 
 const X_AXIS: (f64, f64) = (0., 30.0);
 const Y_AXIS: (f64, f64) = (f64::MAX, 0.);
-const TICK_RATE: u64 = 250;
+pub(super) const TICK_RATE: u64 = 250;
 
 // Stats of a single core
 struct CoreStat {
@@ -57,7 +56,7 @@ pub(crate) struct CpuMonitor {
 }
 
 impl GraphWidget for CpuMonitor {
-    fn update(&mut self) {
+    fn update(&mut self) -> Result<()> {
         // Time since begining
         let elapsed = self.start_time.elapsed().as_secs_f64();
 
@@ -66,8 +65,7 @@ impl GraphWidget for CpuMonitor {
 
         // Update frequencies on cores
         for core in &mut self.stats {
-            // TODO: handle err here somehow
-            let freq = core.update().unwrap();
+            let freq = core.update()?;
             core.add_current(elapsed);
             self.m.set_if_y_max(freq + 100_000.);
             self.m.set_if_y_min(freq + 100_000.);
@@ -76,16 +74,10 @@ impl GraphWidget for CpuMonitor {
         // Set last_time to current time
         self.last_time = Instant::now();
 
-        // Move x axis if time reached end
-        if self.m.time() > self.m.max_x() {
-            let removed = self.stats[0].data.pop();
-            if let Some(point) = self.stats[0].data.first() {
-                self.m.inc_x_axis(point.0 - removed.0);
-            }
-            self.stats.iter_mut().skip(1).for_each(|c| {
-                c.data.pop();
-            });
-        }
+        // Drop points that have fallen outside the visible window and slide
+        // the x axis to track the oldest survivor.
+        self.m.prune_stale(self.stats.iter_mut().map(|c| &mut c.data));
+        Ok(())
     }
     fn render_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
         let chunks = Layout::default()
@@ -117,13 +109,22 @@ impl CpuMonitor {
         })
     }
 
+    /// Per-core `(label, color, current frequency)`, for renderers that skip
+    /// the time-series chart (e.g. the `graph basic` mode).
+    pub(crate) fn core_readouts(&self) -> Vec<(&str, Color, f64)> {
+        self.stats
+            .iter()
+            .map(|c| (c.name.as_str(), c.color, c.core.cur_freq as f64))
+            .collect()
+    }
+
     fn datasets(&self) -> Vec<Dataset> {
         let mut data = Vec::new();
         for core in &self.stats {
             data.push(
                 Dataset::default()
                     .name(&core.name)
-                    .marker(symbols::Marker::Dot)
+                    .marker(self.m.marker())
                     .style(Style::default().fg(core.color))
                     .data(&core.data.data()),
             );
@@ -209,8 +210,8 @@ impl CpuMonitor {
         f.render_widget(second_col, chunks[1]);
     }
 
-    pub(crate) fn graph_loop() -> Result<()> {
-        let mut monitor = CpuMonitor::new(Some(TICK_RATE))?;
+    pub(crate) fn graph_loop(tick_rate: Option<u64>) -> Result<()> {
+        let mut monitor = CpuMonitor::new(Some(tick_rate.unwrap_or(TICK_RATE)))?;
         monitor._graph_loop()
     }
 }
@@ -1,17 +1,120 @@
 use std::{io, sync::mpsc, thread, time::Duration};
-use termion::{event::Key, input::TermRead};
 
 pub(crate) const DEFAULT_TICK_RATE: u64 = 1000;
 
+/// Backend-agnostic key press, decoupling widgets and the event loop from
+/// whichever terminal crate is compiled in (see the `termion`/`crossterm`
+/// feature flags and [`EventSource`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Up,
+    Down,
+    Left,
+    Right,
+    Backspace,
+    Esc,
+    Other,
+}
+
+#[cfg(feature = "termion")]
+impl From<termion::event::Key> for Key {
+    fn from(key: termion::event::Key) -> Key {
+        match key {
+            termion::event::Key::Char(c) => Key::Char(c),
+            termion::event::Key::Up => Key::Up,
+            termion::event::Key::Down => Key::Down,
+            termion::event::Key::Left => Key::Left,
+            termion::event::Key::Right => Key::Right,
+            termion::event::Key::Backspace => Key::Backspace,
+            termion::event::Key::Esc => Key::Esc,
+            _ => Key::Other,
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl From<crossterm::event::KeyEvent> for Key {
+    fn from(event: crossterm::event::KeyEvent) -> Key {
+        use crossterm::event::KeyCode;
+        match event.code {
+            KeyCode::Char(c) => Key::Char(c),
+            // termion reports Tab as Char('\t'); match it so both backends agree.
+            KeyCode::Tab => Key::Char('\t'),
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Esc => Key::Esc,
+            _ => Key::Other,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Event<I> {
     Input(I),
     Tick,
 }
 
+/// Spawns the background thread that turns raw terminal input into
+/// [`Event::Input`]s, one implementation per supported backend.
+trait EventSource {
+    fn spawn(tx: mpsc::Sender<Event<Key>>, exit_key: Key);
+}
+
+#[cfg(feature = "termion")]
+struct TermionEvents;
+#[cfg(feature = "termion")]
+impl EventSource for TermionEvents {
+    fn spawn(tx: mpsc::Sender<Event<Key>>, exit_key: Key) {
+        use termion::input::TermRead;
+        thread::spawn(move || {
+            let stdin = io::stdin();
+            for evt in stdin.keys() {
+                if let Ok(key) = evt {
+                    let key = Key::from(key);
+                    if tx.send(Event::Input(key)).is_err() {
+                        return;
+                    }
+                    if key == exit_key {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+struct CrosstermEvents;
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+impl EventSource for CrosstermEvents {
+    fn spawn(tx: mpsc::Sender<Event<Key>>, exit_key: Key) {
+        thread::spawn(move || loop {
+            if let Ok(crossterm::event::Event::Key(key_event)) = crossterm::event::read() {
+                let key = Key::from(key_event);
+                if tx.send(Event::Input(key)).is_err() {
+                    return;
+                }
+                if key == exit_key {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(feature = "termion")]
+type ActiveEventSource = TermionEvents;
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+type ActiveEventSource = CrosstermEvents;
+
 #[derive(Debug)]
 pub struct Events {
     rx: mpsc::Receiver<Event<Key>>,
+    exit_key: Key,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -48,22 +151,7 @@ impl Default for Config {
 impl Events {
     pub fn with_config(config: Config) -> Events {
         let (tx, rx) = mpsc::channel();
-        let _ = {
-            let tx = tx.clone();
-            thread::spawn(move || {
-                let stdin = io::stdin();
-                for evt in stdin.keys() {
-                    if let Ok(key) = evt {
-                        if let Err(_) = tx.send(Event::Input(key)) {
-                            return;
-                        }
-                        if key == config.exit_key {
-                            return;
-                        }
-                    }
-                }
-            })
-        };
+        ActiveEventSource::spawn(tx.clone(), config.exit_key);
         let _ = {
             thread::spawn(move || loop {
                 if tx.send(Event::Tick).is_err() {
@@ -72,10 +160,17 @@ impl Events {
                 thread::sleep(config.tick_rate);
             })
         };
-        Events { rx }
+        Events {
+            rx,
+            exit_key: config.exit_key,
+        }
     }
 
     pub fn next(&self) -> Result<Event<Key>, mpsc::RecvError> {
         self.rx.recv()
     }
+
+    pub fn exit_key(&self) -> Key {
+        self.exit_key
+    }
 }
@@ -0,0 +1,88 @@
+use super::{
+    common::{DataSeries, GraphWidget, Monitor},
+    events::Config,
+};
+use anyhow::{anyhow, Result};
+use rsys::linux::memory::memory;
+use std::time::Instant;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset},
+    Frame,
+};
+
+const X_AXIS: (f64, f64) = (0., 30.0);
+const Y_AXIS: (f64, f64) = (f64::MAX, 0.);
+pub(super) const TICK_RATE: u64 = 1000;
+
+pub(crate) struct MemoryMonitor {
+    used: DataSeries,
+    start_time: Instant,
+    last_time: Instant,
+    m: Monitor,
+}
+
+impl GraphWidget for MemoryMonitor {
+    fn update(&mut self) -> Result<()> {
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        self.m.add_time(self.last_time.elapsed().as_secs_f64());
+        self.last_time = Instant::now();
+
+        let memory = memory().map_err(|e| anyhow!("Failed to read memory info - {}", e))?;
+        let used_percent = (memory.total - memory.free) as f64 / memory.total as f64 * 100.0;
+
+        self.used.add(elapsed, used_percent);
+        self.m.set_if_y_max(used_percent);
+        self.m.set_if_y_min(used_percent);
+
+        self.m.prune_stale(std::iter::once(&mut self.used));
+        Ok(())
+    }
+
+    fn render_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+        let dataset = Dataset::default()
+            .name("used %")
+            .marker(self.m.marker())
+            .style(Style::default().fg(Color::Magenta))
+            .data(&self.used.data());
+
+        let chart = Chart::new(vec![dataset])
+            .block(
+                Block::default()
+                    .title(Span::styled("Memory Usage", Style::default().fg(Color::Magenta)))
+                    .borders(Borders::ALL),
+            )
+            .x_axis(Axis::default().title("Time").bounds(self.m.x()))
+            .y_axis(
+                Axis::default()
+                    .title("Used %")
+                    .bounds(self.m.y())
+                    .labels(vec![Span::raw("0"), Span::raw("100")]),
+            );
+        f.render_widget(chart, area);
+    }
+
+    fn monitor(&mut self) -> &mut Monitor {
+        &mut self.m
+    }
+}
+
+impl MemoryMonitor {
+    pub(crate) fn new(tick_rate: Option<u64>) -> Result<MemoryMonitor> {
+        memory().map_err(|e| anyhow!("Failed to read memory info - {}", e))?;
+        Ok(MemoryMonitor {
+            used: DataSeries::new(),
+            start_time: Instant::now(),
+            last_time: Instant::now(),
+            m: Monitor::new(X_AXIS, Y_AXIS, Config::new_or_default(tick_rate)),
+        })
+    }
+
+    pub(crate) fn graph_loop(tick_rate: Option<u64>) -> Result<()> {
+        let mut monitor = MemoryMonitor::new(Some(tick_rate.unwrap_or(TICK_RATE)))?;
+        monitor._graph_loop()
+    }
+}
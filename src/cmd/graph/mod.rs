@@ -0,0 +1,142 @@
+mod basic;
+mod common;
+mod cpu;
+pub(crate) mod events;
+mod memory;
+mod network;
+
+use crate::{
+    cli::RsysCli,
+    cmd::show::common::{single_widget_loop, Dashboard, ProcessMonitor, StatefulWidget},
+};
+use basic::BasicMonitor;
+use cpu::CpuMonitor;
+use memory::MemoryMonitor;
+use network::NetworkMonitor;
+use structopt::StructOpt;
+use tui::layout::{Constraint, Direction};
+
+#[derive(StructOpt, Debug, Clone)]
+pub enum GraphCmd {
+    /// Live CPU core frequency graphs
+    Cpu {
+        #[structopt(short, long)]
+        tick_rate: Option<u64>,
+    },
+    /// Live memory usage graph (used memory as a percentage of total)
+    Memory {
+        #[structopt(short, long)]
+        tick_rate: Option<u64>,
+    },
+    /// Live network throughput graphs
+    Network {
+        #[structopt(short, long)]
+        tick_rate: Option<u64>,
+    },
+    /// Tiled dashboard with CPU, memory and network graphs together
+    Dashboard {
+        #[structopt(short, long)]
+        tick_rate: Option<u64>,
+    },
+    /// Sortable, killable process table
+    Processes {
+        #[structopt(short, long)]
+        tick_rate: Option<u64>,
+    },
+    /// Condensed, graphless readout: per-core frequencies and memory/swap
+    /// gauges, for terminals too small for charts
+    Basic {
+        #[structopt(short, long)]
+        tick_rate: Option<u64>,
+    },
+}
+
+impl GraphCmd {
+    /// Resolves a `default_graph` config key (e.g. `"cpu"`) to the widget it
+    /// names, for when `graph` is invoked with no subcommand.
+    pub(crate) fn from_name(name: &str) -> Option<GraphCmd> {
+        match name {
+            "cpu" => Some(GraphCmd::Cpu { tick_rate: None }),
+            "memory" => Some(GraphCmd::Memory { tick_rate: None }),
+            "network" => Some(GraphCmd::Network { tick_rate: None }),
+            "dashboard" => Some(GraphCmd::Dashboard { tick_rate: None }),
+            "processes" => Some(GraphCmd::Processes { tick_rate: None }),
+            "basic" => Some(GraphCmd::Basic { tick_rate: None }),
+            _ => None,
+        }
+    }
+}
+
+impl RsysCli {
+    pub(crate) fn graph(&self, cmd: GraphCmd) {
+        let result = match cmd {
+            GraphCmd::Cpu { tick_rate } => {
+                CpuMonitor::graph_loop(tick_rate.or_else(|| self.config.tick_rate_for("cpu")))
+            }
+            GraphCmd::Memory { tick_rate } => {
+                MemoryMonitor::graph_loop(tick_rate.or_else(|| self.config.tick_rate_for("memory")))
+            }
+            GraphCmd::Network { tick_rate } => {
+                NetworkMonitor::graph_loop(tick_rate.or_else(|| self.config.tick_rate_for("network")))
+            }
+            GraphCmd::Dashboard { tick_rate } => self.dashboard_loop(tick_rate),
+            GraphCmd::Processes { tick_rate } => {
+                let config = events::Config::new_or_default(tick_rate.or_else(|| self.config.tick_rate_for("processes")));
+                ProcessMonitor::new().and_then(|mut monitor| single_widget_loop(&mut monitor, config))
+            }
+            GraphCmd::Basic { tick_rate } => {
+                BasicMonitor::graph_loop(tick_rate.or_else(|| self.config.tick_rate_for("cpu")))
+            }
+        };
+
+        if let Err(e) = result {
+            eprintln!("{}", e);
+        }
+    }
+
+    fn dashboard_loop(&self, tick_rate: Option<u64>) -> anyhow::Result<()> {
+        let cpu_tick = Some(tick_rate.or_else(|| self.config.tick_rate_for("cpu")).unwrap_or(cpu::TICK_RATE));
+        let memory_tick = Some(
+            tick_rate
+                .or_else(|| self.config.tick_rate_for("memory"))
+                .unwrap_or(memory::TICK_RATE),
+        );
+        let network_tick = Some(
+            tick_rate
+                .or_else(|| self.config.tick_rate_for("network"))
+                .unwrap_or(network::TICK_RATE),
+        );
+
+        let cpu_rate = events::Config::new_or_default(cpu_tick).tick_rate;
+        let memory_rate = events::Config::new_or_default(memory_tick).tick_rate;
+        let network_rate = events::Config::new_or_default(network_tick).tick_rate;
+
+        let cpu = Box::new(CpuMonitor::new(cpu_tick)?);
+        let memory = Box::new(MemoryMonitor::new(memory_tick)?);
+        let network = Box::new(NetworkMonitor::new(network_tick)?);
+
+        let mut dashboard = Dashboard::new(Direction::Vertical)
+            .widget(cpu, Constraint::Percentage(50), cpu_rate)
+            .widget(
+                Box::new(
+                    crate::cmd::show::common::Dashboard::new(Direction::Horizontal)
+                        .widget(memory, Constraint::Percentage(50), memory_rate)
+                        .widget(network, Constraint::Percentage(50), network_rate),
+                ),
+                Constraint::Percentage(50),
+                memory_rate.min(network_rate),
+            );
+
+        // The shared input/render loop must tick at least as often as the
+        // fastest widget, or that widget's own tick_rate would never get a
+        // chance to fire.
+        let driver_rate = cpu_rate.min(memory_rate).min(network_rate);
+        crate::cmd::show::common::multi_widget_loop(
+            &mut dashboard,
+            events::Config {
+                tick_rate: driver_rate,
+                ..events::Config::new_or_default(tick_rate.or_else(|| self.config.tick_rate))
+            },
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use super::{
+    common::{DataSeries, GraphWidget, Monitor},
+    events::Config,
+};
+use anyhow::{anyhow, Result};
+use rsys::linux::network::rx_tx;
+use std::time::Instant;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset},
+    Frame,
+};
+
+const X_AXIS: (f64, f64) = (0., 30.0);
+const Y_AXIS: (f64, f64) = (f64::MAX, 0.);
+pub(super) const TICK_RATE: u64 = 1000;
+
+pub(crate) struct NetworkMonitor {
+    rx: DataSeries,
+    tx: DataSeries,
+    start_time: Instant,
+    last_time: Instant,
+    m: Monitor,
+}
+
+impl GraphWidget for NetworkMonitor {
+    fn update(&mut self) -> Result<()> {
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        self.m.add_time(self.last_time.elapsed().as_secs_f64());
+        self.last_time = Instant::now();
+
+        let (rx, tx) = rx_tx().map_err(|e| anyhow!("Failed to read network throughput - {}", e))?;
+
+        self.rx.add(elapsed, rx as f64);
+        self.tx.add(elapsed, tx as f64);
+        self.m.set_if_y_max(rx.max(tx) as f64);
+        self.m.set_if_y_min(rx.min(tx) as f64);
+
+        self.m.prune_stale([&mut self.rx, &mut self.tx]);
+        Ok(())
+    }
+
+    fn render_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+        let datasets = vec![
+            Dataset::default()
+                .name("rx")
+                .marker(self.m.marker())
+                .style(Style::default().fg(Color::Green))
+                .data(&self.rx.data()),
+            Dataset::default()
+                .name("tx")
+                .marker(self.m.marker())
+                .style(Style::default().fg(Color::Blue))
+                .data(&self.tx.data()),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(Span::styled("Network Throughput", Style::default().fg(Color::Green)))
+                    .borders(Borders::ALL),
+            )
+            .x_axis(Axis::default().title("Time").bounds(self.m.x()))
+            .y_axis(Axis::default().title("Bytes/s").bounds(self.m.y()));
+        f.render_widget(chart, area);
+    }
+
+    fn monitor(&mut self) -> &mut Monitor {
+        &mut self.m
+    }
+}
+
+impl NetworkMonitor {
+    pub(crate) fn new(tick_rate: Option<u64>) -> Result<NetworkMonitor> {
+        Ok(NetworkMonitor {
+            rx: DataSeries::new(),
+            tx: DataSeries::new(),
+            start_time: Instant::now(),
+            last_time: Instant::now(),
+            m: Monitor::new(X_AXIS, Y_AXIS, Config::new_or_default(tick_rate)),
+        })
+    }
+
+    pub(crate) fn graph_loop(tick_rate: Option<u64>) -> Result<()> {
+        let mut monitor = NetworkMonitor::new(Some(tick_rate.unwrap_or(TICK_RATE)))?;
+        monitor._graph_loop()
+    }
+}
@@ -0,0 +1,86 @@
+pub(crate) mod graph;
+pub(crate) mod show;
+
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+pub use graph::GraphCmd;
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "rsys", about = "Gather and monitor information about your system")]
+pub struct RsysOpt {
+    #[structopt(subcommand)]
+    pub cmd: Option<RsysCmd>,
+
+    /// Path to a TOML config file with default flags, tick rates and graph
+    /// selection. CLI flags always win over values read from it. If the
+    /// file doesn't exist yet it is created with commented defaults.
+    #[structopt(short = "C", long = "config")]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(StructOpt, Debug, Clone)]
+pub enum RsysCmd {
+    /// Print a single system property
+    Get {
+        property: String,
+        #[structopt(short, long)]
+        json: bool,
+        #[structopt(short, long)]
+        yaml: bool,
+        #[structopt(short, long)]
+        pretty: bool,
+    },
+    /// Dump a snapshot of system information
+    Dump {
+        #[structopt(short, long)]
+        json: bool,
+        #[structopt(short, long)]
+        yaml: bool,
+        #[structopt(short, long)]
+        pretty: bool,
+        #[structopt(long)]
+        cpu: bool,
+        #[structopt(long)]
+        memory: bool,
+        #[structopt(long)]
+        network: bool,
+        #[structopt(long)]
+        storage: bool,
+        #[structopt(long)]
+        mounts: bool,
+        #[structopt(long)]
+        all: bool,
+        #[structopt(long)]
+        stats: bool,
+        #[structopt(long)]
+        processes: bool,
+    },
+    /// Periodically print system information
+    Watch {
+        #[structopt(short, long)]
+        pretty: bool,
+        #[structopt(long)]
+        cpu: bool,
+        #[structopt(long)]
+        memory: bool,
+        #[structopt(long)]
+        network: bool,
+        #[structopt(long)]
+        storage: bool,
+        #[structopt(long)]
+        all: bool,
+        #[structopt(long)]
+        stats: bool,
+        #[structopt(short, long)]
+        duration: Option<u64>,
+        #[structopt(short, long)]
+        interval: Option<u64>,
+    },
+    /// Launch an interactive TUI graph. If no widget is given, the
+    /// `default_graph` configured via `--config` is used instead.
+    Graph {
+        #[structopt(subcommand)]
+        graph: Option<GraphCmd>,
+    },
+}
@@ -0,0 +1,98 @@
+use super::{events::Key, RsysBackend, StatefulWidget};
+use anyhow::Result;
+use std::time::{Duration, Instant};
+use tui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
+
+/// A tiled widget paired with its own update cadence, so it can tick slower
+/// or faster than its neighbours rather than lockstep with the dashboard's
+/// shared render/input tick.
+struct Tile {
+    widget: Box<dyn StatefulWidget>,
+    tick_rate: Duration,
+    last_update: Instant,
+}
+
+/// Tiles several [`StatefulWidget`]s on screen, each updating on its own
+/// `tick_rate`, mirroring bottom/gotop's modular multi-widget screens.
+/// `Tab` cycles which widget is focused and `f` maximizes it to full screen
+/// (pressing `f` again restores the tiled layout).
+pub struct Dashboard {
+    direction: Direction,
+    constraints: Vec<Constraint>,
+    tiles: Vec<Tile>,
+    focused: usize,
+    maximized: bool,
+}
+
+impl Dashboard {
+    pub fn new(direction: Direction) -> Dashboard {
+        Dashboard {
+            direction,
+            constraints: Vec::new(),
+            tiles: Vec::new(),
+            focused: 0,
+            maximized: false,
+        }
+    }
+
+    /// Adds `widget`, occupying `constraint`'s share of the dashboard area
+    /// and calling `update()` on its own `tick_rate` instead of every time
+    /// the dashboard itself ticks.
+    pub fn widget(mut self, widget: Box<dyn StatefulWidget>, constraint: Constraint, tick_rate: Duration) -> Dashboard {
+        self.tiles.push(Tile {
+            widget,
+            tick_rate,
+            // Due immediately on the first tick.
+            last_update: Instant::now() - tick_rate,
+        });
+        self.constraints.push(constraint);
+        self
+    }
+}
+
+impl StatefulWidget for Dashboard {
+    fn update(&mut self) -> Result<()> {
+        for tile in &mut self.tiles {
+            if tile.last_update.elapsed() >= tile.tick_rate {
+                tile.widget.update()?;
+                tile.last_update = Instant::now();
+            }
+        }
+        Ok(())
+    }
+
+    fn render_widget(&self, f: &mut Frame<RsysBackend>, area: Rect) {
+        if self.maximized {
+            if let Some(tile) = self.tiles.get(self.focused) {
+                tile.widget.render_widget(f, area);
+            }
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(self.direction)
+            .constraints(self.constraints.as_slice())
+            .split(area);
+
+        for (tile, chunk) in self.tiles.iter().zip(chunks) {
+            tile.widget.render_widget(f, chunk);
+        }
+    }
+
+    fn on_key(&mut self, key: Key) -> bool {
+        match key {
+            Key::Char('\t') if !self.tiles.is_empty() => {
+                self.focused = (self.focused + 1) % self.tiles.len();
+                true
+            }
+            Key::Char('f') => {
+                self.maximized = !self.maximized;
+                true
+            }
+            key => self.tiles.get_mut(self.focused).map_or(false, |t| t.widget.on_key(key)),
+        }
+    }
+}
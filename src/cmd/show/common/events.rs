@@ -0,0 +1 @@
+pub(crate) use crate::cmd::graph::events::{Config, Event, Events, Key};
@@ -0,0 +1,98 @@
+mod dashboard;
+pub(crate) mod events;
+pub(crate) mod process;
+pub(crate) mod widget;
+
+pub use dashboard::Dashboard;
+pub(crate) use process::ProcessMonitor;
+
+use anyhow::Result;
+use std::io;
+use tui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame, Terminal,
+};
+
+pub use widget::*;
+
+/// The concrete backend every widget in this module renders against. Kept
+/// as a single alias (rather than a generic `B: Backend` on every widget
+/// method) so widgets can be stored as `Box<dyn StatefulWidget>` in a
+/// [`Dashboard`]. Picked by the `termion`/`crossterm` feature flags, same
+/// as the event source in [`events`].
+#[cfg(feature = "termion")]
+pub(crate) type RsysBackend =
+    tui::backend::TermionBackend<termion::screen::AlternateScreen<termion::raw::RawTerminal<std::io::Stdout>>>;
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+pub(crate) type RsysBackend = tui::backend::CrosstermBackend<std::io::Stdout>;
+
+pub(crate) type RsysTerminal = Terminal<RsysBackend>;
+
+/// Visible x/y bounds of a chart, used by [`GraphWidget`] implementors.
+#[derive(Debug, Clone, Copy)]
+pub struct Screen {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+impl Screen {
+    pub fn new(x: (f64, f64), y: (f64, f64)) -> Screen {
+        Screen { x, y }
+    }
+    pub fn x(&self) -> (f64, f64) {
+        self.x
+    }
+    pub fn y(&self) -> (f64, f64) {
+        self.y
+    }
+}
+
+#[cfg(feature = "termion")]
+pub(crate) fn get_terminal() -> Result<RsysTerminal> {
+    use termion::{raw::IntoRawMode, screen::AlternateScreen};
+    let stdout = io::stdout().into_raw_mode()?;
+    let stdout = AlternateScreen::from(stdout);
+    let backend = tui::backend::TermionBackend::new(stdout);
+    Ok(Terminal::new(backend)?)
+}
+
+#[cfg(all(feature = "crossterm", not(feature = "termion")))]
+pub(crate) fn get_terminal() -> Result<RsysTerminal> {
+    use crossterm::{execute, terminal::{enable_raw_mode, EnterAlternateScreen}};
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = tui::backend::CrosstermBackend::new(stdout);
+    Ok(Terminal::new(backend)?)
+}
+
+pub(crate) fn err_popup(f: &mut Frame<RsysBackend>, msg: &str, hint: &str) {
+    let area = centered_rect(50, 20, f.size());
+    let block = Block::default()
+        .title(Span::styled("Error", Style::default().fg(Color::Red)))
+        .borders(Borders::ALL);
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(format!("{}\n\n{}", msg, hint)).block(block), area);
+}
+
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
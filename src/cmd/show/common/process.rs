@@ -0,0 +1,220 @@
+use super::{err_popup, events::Key, RsysBackend, StatefulWidget};
+use anyhow::{anyhow, Result};
+use nix::{
+    sys::signal::{kill, Signal},
+    unistd::Pid,
+};
+use rsys::linux::process::processes;
+use std::collections::HashMap;
+use tui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Row, Table, TableState},
+    Frame,
+};
+
+/// Column processes can be sorted by, cycled with `s`; `r` flips direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProcessSorting {
+    Cpu,
+    Mem,
+    Pid,
+    Name,
+}
+impl ProcessSorting {
+    fn next(self) -> ProcessSorting {
+        match self {
+            ProcessSorting::Cpu => ProcessSorting::Mem,
+            ProcessSorting::Mem => ProcessSorting::Pid,
+            ProcessSorting::Pid => ProcessSorting::Name,
+            ProcessSorting::Name => ProcessSorting::Cpu,
+        }
+    }
+}
+
+struct ProcessRow {
+    pid: i32,
+    name: String,
+    cpu_percent: f64,
+    memory: u64,
+}
+
+/// `utime + stime` and total jiffies for a PID at the last tick, so CPU%
+/// can be derived via the standard delta method: `(proc_delta / total_delta) * 100`.
+#[derive(Clone, Copy)]
+struct JiffiesSample {
+    proc_jiffies: u64,
+    total_jiffies: u64,
+}
+
+/// Sortable, killable process table, reading stats from `rsys`.
+pub(crate) struct ProcessMonitor {
+    rows: Vec<ProcessRow>,
+    prev: HashMap<i32, JiffiesSample>,
+    sort: ProcessSorting,
+    reverse: bool,
+    state: TableState,
+    pending_kill: Option<i32>,
+}
+
+impl ProcessMonitor {
+    pub(crate) fn new() -> Result<ProcessMonitor> {
+        let mut monitor = ProcessMonitor {
+            rows: Vec::new(),
+            prev: HashMap::new(),
+            sort: ProcessSorting::Cpu,
+            reverse: true,
+            state: TableState::default(),
+            pending_kill: None,
+        };
+        monitor.refresh()?;
+        Ok(monitor)
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        let procs = processes().map_err(|e| anyhow!("Failed to read processes - {}", e))?;
+        let mut prev = HashMap::with_capacity(procs.len());
+        let mut rows = Vec::with_capacity(procs.len());
+
+        for proc in procs {
+            let proc_jiffies = proc.utime + proc.stime;
+            let total_jiffies = proc.total_jiffies;
+            let cpu_percent = match self.prev.get(&proc.pid) {
+                Some(last) => {
+                    let proc_delta = proc_jiffies.saturating_sub(last.proc_jiffies) as f64;
+                    let total_delta = total_jiffies.saturating_sub(last.total_jiffies) as f64;
+                    if total_delta > 0.0 {
+                        (proc_delta / total_delta) * 100.0
+                    } else {
+                        0.0
+                    }
+                }
+                None => 0.0,
+            };
+
+            prev.insert(
+                proc.pid,
+                JiffiesSample {
+                    proc_jiffies,
+                    total_jiffies,
+                },
+            );
+            rows.push(ProcessRow {
+                pid: proc.pid,
+                name: proc.name,
+                cpu_percent,
+                memory: proc.memory,
+            });
+        }
+
+        self.prev = prev;
+        self.rows = rows;
+        self.sort_rows();
+        Ok(())
+    }
+
+    fn sort_rows(&mut self) {
+        let reverse = self.reverse;
+        self.rows.sort_by(|a, b| {
+            let ordering = match self.sort {
+                ProcessSorting::Cpu => a.cpu_percent.partial_cmp(&b.cpu_percent).unwrap(),
+                ProcessSorting::Mem => a.memory.cmp(&b.memory),
+                ProcessSorting::Pid => a.pid.cmp(&b.pid),
+                ProcessSorting::Name => a.name.cmp(&b.name),
+            };
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    fn scroll(&mut self, delta: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0) as isize;
+        let next = (current + delta).rem_euclid(self.rows.len() as isize) as usize;
+        self.state.select(Some(next));
+    }
+
+    fn kill_selected(&mut self, pid: i32, signal: Signal) {
+        let _ = kill(Pid::from_raw(pid), signal);
+    }
+}
+
+impl StatefulWidget for ProcessMonitor {
+    fn update(&mut self) -> Result<()> {
+        self.refresh()
+    }
+
+    fn render_widget(&self, f: &mut Frame<RsysBackend>, area: Rect) {
+        let header = Row::new(vec!["PID", "Name", "CPU%", "Mem (KB)"]).style(Style::default().add_modifier(Modifier::BOLD));
+        let rows = self.rows.iter().map(|row| {
+            Row::new(vec![
+                row.pid.to_string(),
+                row.name.clone(),
+                format!("{:.1}", row.cpu_percent),
+                row.memory.to_string(),
+            ])
+        });
+
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().title("Processes").borders(Borders::ALL))
+            .widths(&[
+                Constraint::Length(8),
+                Constraint::Percentage(50),
+                Constraint::Length(8),
+                Constraint::Length(12),
+            ])
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        // Table is rendered via render_stateful_widget, so it needs its own
+        // mutable copy of the selection state rather than `&self`'s.
+        let mut state = self.state.clone();
+        f.render_stateful_widget(table, area, &mut state);
+
+        if let Some(pid) = self.pending_kill {
+            err_popup(
+                f,
+                &format!("Kill process {}?", pid),
+                "`y` SIGTERM, `K` SIGKILL, any other key cancels.",
+            );
+        }
+    }
+
+    fn on_key(&mut self, key: Key) -> bool {
+        if let Some(pid) = self.pending_kill.take() {
+            match key {
+                Key::Char('y') => self.kill_selected(pid, Signal::SIGTERM),
+                Key::Char('K') => self.kill_selected(pid, Signal::SIGKILL),
+                _ => {}
+            }
+            return true;
+        }
+
+        match key {
+            Key::Down | Key::Char('j') => self.scroll(1),
+            Key::Up | Key::Char('k') => self.scroll(-1),
+            Key::Char('s') => {
+                self.sort = self.sort.next();
+                self.sort_rows();
+            }
+            Key::Char('r') => {
+                self.reverse = !self.reverse;
+                self.sort_rows();
+            }
+            Key::Char('d') => {
+                if let Some(i) = self.state.selected() {
+                    if let Some(row) = self.rows.get(i) {
+                        self.pending_kill = Some(row.pid);
+                    }
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
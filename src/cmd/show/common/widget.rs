@@ -1,12 +1,11 @@
 use super::{
     err_popup,
-    events::{Config, Event, Events},
-    get_terminal, Screen,
+    events::{Config, Event, Events, Key},
+    get_terminal, RsysBackend, Screen,
 };
 use anyhow::Result;
 use std::borrow::Cow;
 use tui::{
-    backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
     text::Span,
@@ -18,7 +17,14 @@ use tui::{
 /// together providing functionality like single_widget_loop.
 pub trait StatefulWidget {
     fn update(&mut self) -> Result<()>;
-    fn render_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect);
+    fn render_widget(&self, f: &mut Frame<RsysBackend>, area: Rect);
+
+    /// Handles a key not already claimed by the loop driving this widget
+    /// (pause, exit, ...). Returns whether the key was handled, so widgets
+    /// that don't care about input can just keep the default no-op.
+    fn on_key(&mut self, _key: Key) -> bool {
+        false
+    }
 }
 
 /// Trait providing more readable way of creating graph widgets
@@ -43,7 +49,7 @@ pub trait GraphWidget {
                     .bounds(self.monitor().y()),
             )
     }
-    fn render_graph_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+    fn render_graph_widget(&self, f: &mut Frame<RsysBackend>, area: Rect) {
         let chart = self.chart();
         f.render_widget(chart, area);
     }
@@ -52,9 +58,9 @@ pub trait GraphWidget {
 pub trait InfoGraphWidget: GraphWidget {
     const DIRECTION: Direction;
     const CONSTRAINTS: [Constraint; 2];
-    fn render_extra_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect);
+    fn render_extra_widget(&self, f: &mut Frame<RsysBackend>, area: Rect);
 
-    fn render_widget<B: Backend>(&self, f: &mut Frame<B>, area: Rect) {
+    fn render_widget(&self, f: &mut Frame<RsysBackend>, area: Rect) {
         let chunks = Layout::default()
             .direction(Self::DIRECTION)
             .constraints(Self::CONSTRAINTS)
@@ -69,11 +75,14 @@ pub trait Updatable {
     fn update(&mut self) -> Result<()>;
 }
 
-/// Loop a single widget on full screen endlessly
+/// Loop a single widget on full screen endlessly. Space pauses ticking
+/// (the widget stops receiving `update()` calls until unpaused); every
+/// other key is forwarded to [`StatefulWidget::on_key`].
 pub fn single_widget_loop<W: StatefulWidget>(widget: &mut W, config: Config) -> Result<()> {
     let mut terminal = get_terminal()?;
     let events = Events::with_config(config);
     let mut err_msg: Option<String> = None;
+    let mut paused = false;
     loop {
         terminal.draw(|f| {
             let size = f.size();
@@ -90,10 +99,17 @@ pub fn single_widget_loop<W: StatefulWidget>(widget: &mut W, config: Config) ->
                 if input == events.exit_key() {
                     break;
                 }
+                if input == Key::Char(' ') {
+                    paused = !paused;
+                } else {
+                    widget.on_key(input);
+                }
             }
             Event::Tick => {
-                if let Err(e) = widget.update() {
-                    err_msg = Some(e.to_string());
+                if !paused {
+                    if let Err(e) = widget.update() {
+                        err_msg = Some(e.to_string());
+                    }
                 }
             }
         }
@@ -101,6 +117,13 @@ pub fn single_widget_loop<W: StatefulWidget>(widget: &mut W, config: Config) ->
     Ok(())
 }
 
+/// Loop a [`Dashboard`](super::Dashboard) of tiled widgets on full screen,
+/// rendering all of them together; each tile is updated on its own
+/// `tick_rate` (see [`Dashboard::widget`](super::Dashboard::widget)).
+pub fn multi_widget_loop(dashboard: &mut super::Dashboard, config: Config) -> Result<()> {
+    single_widget_loop(dashboard, config)
+}
+
 pub struct GraphSettings<'t, 'l> {
     pub title: Span<'t>,
     pub x_title: Span<'t>,
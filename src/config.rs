@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// Commented-out defaults written out the first time a `--config` path is
+/// passed but doesn't exist yet, so users have something to uncomment
+/// rather than an empty file.
+const DEFAULT_CONFIG: &str = r#"# rsys-cli configuration
+#
+# Any flag set on the command line always takes priority over the value
+# configured here.
+
+# tick_rate = 1000
+# pretty = false
+# default_format = "normal"
+# default_graph = "cpu"
+
+[tick_rates]
+# cpu = 250
+# memory = 1000
+# network = 1000
+"#;
+
+/// Mirrors the `--json`/`--yaml`/plain trio of flags so it can be set once
+/// in the config file instead of on every invocation.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultFormat {
+    Json,
+    Yaml,
+    Normal,
+}
+
+/// Per-widget tick rate overrides, keyed by the same name used in
+/// `default_graph` and on `Graph` subcommands.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TickRates {
+    pub cpu: Option<u64>,
+    pub memory: Option<u64>,
+    pub network: Option<u64>,
+}
+
+/// Persisted defaults for flags that are otherwise retyped on every
+/// invocation. CLI flags always win when both are set; see
+/// [`RsysConfig::tick_rate_for`] for how per-widget and top-level tick
+/// rates are merged.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RsysConfig {
+    pub tick_rate: Option<u64>,
+    pub pretty: Option<bool>,
+    pub default_format: Option<DefaultFormat>,
+    pub default_graph: Option<String>,
+    #[serde(default)]
+    pub tick_rates: TickRates,
+}
+
+impl RsysConfig {
+    /// Loads the config at `path`. If no file exists there yet, one is
+    /// created with commented defaults and an empty config is returned,
+    /// so a user can discover and fill in the file on their next run.
+    pub fn load_or_create(path: &Path) -> Result<RsysConfig> {
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create `{}`", parent.display()))?;
+                }
+            }
+            fs::write(path, DEFAULT_CONFIG)
+                .with_context(|| format!("Failed to create config at `{}`", path.display()))?;
+            return Ok(RsysConfig::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config at `{}`", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse config at `{}`", path.display()))
+    }
+
+    /// Resolves the tick rate to use for `widget` (e.g. `"cpu"`), preferring
+    /// a per-widget override over the top-level `tick_rate`. Callers still
+    /// need to prefer an explicit CLI flag over whatever this returns.
+    pub fn tick_rate_for(&self, widget: &str) -> Option<u64> {
+        let per_widget = match widget {
+            "cpu" => self.tick_rates.cpu,
+            "memory" => self.tick_rates.memory,
+            "network" => self.tick_rates.network,
+            _ => None,
+        };
+        per_widget.or(self.tick_rate)
+    }
+}